@@ -0,0 +1,41 @@
+use once_cell::sync::Lazy;
+use std::sync::mpsc::{channel, Sender};
+use std::thread;
+
+/// Sounds that can be requested through the shared alert channel. New
+/// alarm-style features should add a variant here rather than spawning
+/// their own playback thread.
+#[derive(Debug, Clone, Copy)]
+pub enum Alert {
+    TimerExpired,
+}
+
+static ALERT_SENDER: Lazy<Sender<Alert>> = Lazy::new(|| {
+    let (tx, rx) = channel::<Alert>();
+    thread::spawn(move || {
+        for alert in rx {
+            play(alert);
+        }
+    });
+    tx
+});
+
+fn play(alert: Alert) {
+    let sound = match alert {
+        Alert::TimerExpired => "complete",
+    };
+
+    if let Err(err) = std::process::Command::new("canberra-gtk-play")
+        .args(["-i", sound])
+        .status()
+    {
+        log::warn!("Failed to play alert sound '{}': {}", sound, err);
+    }
+}
+
+/// Returns a clone of the process-wide sender used to request alert
+/// sounds. All alarm playback is routed through the one background
+/// thread behind it.
+pub fn alert_sender() -> Sender<Alert> {
+    ALERT_SENDER.clone()
+}
@@ -0,0 +1,82 @@
+/// A duration decomposed into zero-padded, display-ready fields.
+///
+/// Every field except the last one, `centiseconds`, carries a trailing
+/// left-to-right mark (`\u{200E}`) so the `:` separators between them
+/// keep their visual order under RTL locales.
+pub struct RenderedDuration {
+    pub hours: String,
+    pub minutes: String,
+    pub seconds: String,
+    pub centiseconds: String,
+}
+
+/// Decomposes `total_seconds` into hours, remainder-minutes,
+/// remainder-seconds and centiseconds, formatting each as a fixed-width
+/// `%02d` field (hours is left unpadded, matching the existing display).
+///
+/// Unlike `chrono::Duration::num_minutes`/`num_seconds`, which each
+/// return the *total* elapsed amount in that unit, this rolls over: at
+/// one minute elapsed the seconds field reads `00`, not `60`.
+pub fn render_duration(total_seconds: f64) -> RenderedDuration {
+    let total_seconds = total_seconds.max(0.0);
+    let whole_seconds = total_seconds.floor() as i64;
+
+    let hours = whole_seconds / 3600;
+    let minutes = (whole_seconds / 60) % 60;
+    let seconds = whole_seconds % 60;
+    let centiseconds = (total_seconds.fract() * 100.0).floor() as i64 % 100;
+
+    RenderedDuration {
+        hours: format!("{}\u{200E}", hours),
+        minutes: format!("{:02}\u{200E}", minutes),
+        seconds: format!("{:02}\u{200E}", seconds),
+        centiseconds: format!("{:02}", centiseconds),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(total_seconds: f64) -> (String, String, String, String) {
+        let rendered = render_duration(total_seconds);
+        (
+            rendered.hours.trim_end_matches('\u{200E}').to_string(),
+            rendered.minutes.trim_end_matches('\u{200E}').to_string(),
+            rendered.seconds.trim_end_matches('\u{200E}').to_string(),
+            rendered.centiseconds,
+        )
+    }
+
+    #[test]
+    fn just_under_a_minute_does_not_roll_over() {
+        assert_eq!(
+            fields(59.99),
+            ("0".into(), "00".into(), "59".into(), "99".into())
+        );
+    }
+
+    #[test]
+    fn a_minute_rolls_seconds_over_to_zero() {
+        assert_eq!(
+            fields(60.0),
+            ("0".into(), "01".into(), "00".into(), "00".into())
+        );
+    }
+
+    #[test]
+    fn an_hour_and_a_minute_and_a_second() {
+        assert_eq!(
+            fields(3661.0),
+            ("1".into(), "01".into(), "01".into(), "00".into())
+        );
+    }
+
+    #[test]
+    fn negative_durations_clamp_to_zero() {
+        assert_eq!(
+            fields(-5.0),
+            ("0".into(), "00".into(), "00".into(), "00".into())
+        );
+    }
+}
@@ -0,0 +1,193 @@
+mod imp {
+    use glib::{ParamSpec, ParamSpecBoolean, ParamSpecDouble, ParamSpecInt, Value};
+    use gtk::{glib, prelude::*, subclass::prelude::*};
+    use once_cell::sync::Lazy;
+    use std::cell::Cell;
+
+    #[derive(Default)]
+    pub struct StopwatchLap {
+        pub index: Cell<i32>,
+        pub duration: Cell<f64>,
+        pub delta: Cell<f64>,
+        pub has_delta: Cell<bool>,
+        pub is_fastest: Cell<bool>,
+        pub is_slowest: Cell<bool>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for StopwatchLap {
+        const NAME: &'static str = "NixieStopwatchLap";
+        type Type = super::StopwatchLap;
+    }
+
+    impl ObjectImpl for StopwatchLap {
+        fn properties() -> &'static [ParamSpec] {
+            static PROPERTIES: Lazy<Vec<ParamSpec>> = Lazy::new(|| {
+                vec![
+                    ParamSpecInt::builder("index").build(),
+                    ParamSpecDouble::builder("duration").build(),
+                    ParamSpecDouble::builder("delta").build(),
+                    ParamSpecBoolean::builder("has-delta").build(),
+                    ParamSpecBoolean::builder("is-fastest").build(),
+                    ParamSpecBoolean::builder("is-slowest").build(),
+                ]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(&self, _obj: &Self::Type, _id: usize, value: &Value, pspec: &ParamSpec) {
+            match pspec.name() {
+                "index" => self.index.set(value.get().unwrap()),
+                "duration" => self.duration.set(value.get().unwrap()),
+                "delta" => self.delta.set(value.get().unwrap()),
+                "has-delta" => self.has_delta.set(value.get().unwrap()),
+                "is-fastest" => self.is_fastest.set(value.get().unwrap()),
+                "is-slowest" => self.is_slowest.set(value.get().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, _obj: &Self::Type, _id: usize, pspec: &ParamSpec) -> Value {
+            match pspec.name() {
+                "index" => self.index.get().to_value(),
+                "duration" => self.duration.get().to_value(),
+                "delta" => self.delta.get().to_value(),
+                "has-delta" => self.has_delta.get().to_value(),
+                "is-fastest" => self.is_fastest.get().to_value(),
+                "is-slowest" => self.is_slowest.get().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+    }
+}
+
+use crate::duration::{render_duration, RenderedDuration};
+use gtk::glib;
+
+glib::wrapper! {
+    pub struct StopwatchLap(ObjectSubclass<imp::StopwatchLap>);
+}
+
+impl StopwatchLap {
+    pub fn new(duration: f64, index: i32) -> Self {
+        glib::Object::new(&[("duration", &duration), ("index", &index)])
+            .expect("Failed to create StopwatchLap")
+    }
+
+    /// Zero-padded rendering of this lap's absolute duration, using the
+    /// same `render_duration` helper as the live stopwatch display.
+    pub fn rendered_duration(&self) -> RenderedDuration {
+        render_duration(self.property_value("duration").get::<f64>().unwrap())
+    }
+
+    /// Zero-padded rendering of the magnitude of the delta against the
+    /// previous lap, or `None` for the first lap (which has no
+    /// predecessor to compare against). The sign itself is conveyed by
+    /// the `positive-lap`/`negative-lap` CSS classes, not by this text.
+    pub fn rendered_delta(&self) -> Option<RenderedDuration> {
+        if self.property_value("has-delta").get::<bool>().unwrap() {
+            let delta = self.property_value("delta").get::<f64>().unwrap();
+            Some(render_duration(delta.abs()))
+        } else {
+            None
+        }
+    }
+
+    /// Records the signed delta against the lap immediately before this
+    /// one. The first lap never has a delta.
+    pub fn set_delta(&self, delta: f64) {
+        self.set_property("delta", delta);
+        self.set_property("has-delta", true);
+    }
+
+    pub fn set_fastest(&self, is_fastest: bool) {
+        self.set_property("is-fastest", is_fastest);
+    }
+
+    pub fn set_slowest(&self, is_slowest: bool) {
+        self.set_property("is-slowest", is_slowest);
+    }
+}
+
+impl Default for StopwatchLap {
+    fn default() -> Self {
+        Self::new(0.0, 0)
+    }
+}
+
+/// Computes the signed delta of `duration` against the lap immediately
+/// before it, or `None` for the first lap (which has no predecessor to
+/// compare against).
+pub fn lap_delta(duration: f64, previous_duration: Option<f64>) -> Option<f64> {
+    previous_duration.map(|previous| duration - previous)
+}
+
+/// Returns the positions of every lap tied for fastest (min) and every
+/// lap tied for slowest (max) duration, or two empty lists when there
+/// are fewer than two laps to compare. Ties are returned in full rather
+/// than picking a single winner, so two laps reading the same time are
+/// highlighted the same way instead of one arbitrarily winning.
+pub fn lap_extrema(durations: &[f64]) -> (Vec<usize>, Vec<usize>) {
+    if durations.len() < 2 {
+        return (Vec::new(), Vec::new());
+    }
+
+    let fastest_duration = durations.iter().copied().fold(f64::INFINITY, f64::min);
+    let slowest_duration = durations.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    let fastest = durations
+        .iter()
+        .enumerate()
+        .filter(|(_, &duration)| duration == fastest_duration)
+        .map(|(i, _)| i)
+        .collect();
+    let slowest = durations
+        .iter()
+        .enumerate()
+        .filter(|(_, &duration)| duration == slowest_duration)
+        .map(|(i, _)| i)
+        .collect();
+
+    (fastest, slowest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_lap_has_no_delta() {
+        assert_eq!(lap_delta(12.0, None), None);
+    }
+
+    #[test]
+    fn delta_is_signed_against_the_previous_lap() {
+        assert_eq!(lap_delta(12.0, Some(10.0)), Some(2.0));
+        assert_eq!(lap_delta(8.0, Some(10.0)), Some(-2.0));
+    }
+
+    #[test]
+    fn fewer_than_two_laps_have_no_extrema() {
+        assert_eq!(lap_extrema(&[]), (vec![], vec![]));
+        assert_eq!(lap_extrema(&[10.0]), (vec![], vec![]));
+    }
+
+    #[test]
+    fn extrema_pick_the_fastest_and_slowest_lap() {
+        // Laps are stored newest-first, as StopwatchPage::lap() inserts them.
+        let durations = [9.0, 11.0, 10.0, 12.0];
+        assert_eq!(lap_extrema(&durations), (vec![0], vec![3]));
+    }
+
+    #[test]
+    fn ties_tag_every_matching_lap() {
+        let durations = [10.0, 10.0, 12.0];
+        assert_eq!(lap_extrema(&durations), (vec![0, 1], vec![2]));
+    }
+
+    #[test]
+    fn all_equal_laps_tie_for_both_extrema() {
+        let durations = [10.0, 10.0];
+        assert_eq!(lap_extrema(&durations), (vec![0, 1], vec![0, 1]));
+    }
+}
@@ -0,0 +1,75 @@
+mod imp {
+    use glib::{ParamSpec, ParamSpecDouble, ParamSpecString, Value};
+    use gtk::{glib, prelude::*, subclass::prelude::*};
+    use once_cell::sync::Lazy;
+    use std::cell::{Cell, RefCell};
+
+    #[derive(Default)]
+    pub struct TimerPreset {
+        pub name: RefCell<String>,
+        pub duration: Cell<f64>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for TimerPreset {
+        const NAME: &'static str = "NixieTimerPreset";
+        type Type = super::TimerPreset;
+    }
+
+    impl ObjectImpl for TimerPreset {
+        fn properties() -> &'static [ParamSpec] {
+            static PROPERTIES: Lazy<Vec<ParamSpec>> = Lazy::new(|| {
+                vec![
+                    ParamSpecString::builder("name").build(),
+                    ParamSpecDouble::builder("duration").build(),
+                ]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(&self, _obj: &Self::Type, _id: usize, value: &Value, pspec: &ParamSpec) {
+            match pspec.name() {
+                "name" => {
+                    self.name.replace(value.get().unwrap());
+                }
+                "duration" => self.duration.set(value.get().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, _obj: &Self::Type, _id: usize, pspec: &ParamSpec) -> Value {
+            match pspec.name() {
+                "name" => self.name.borrow().to_value(),
+                "duration" => self.duration.get().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+    }
+}
+
+use gtk::glib;
+
+glib::wrapper! {
+    pub struct TimerPreset(ObjectSubclass<imp::TimerPreset>);
+}
+
+impl TimerPreset {
+    pub fn new(name: &str, duration: f64) -> Self {
+        glib::Object::new(&[("name", &name), ("duration", &duration)])
+            .expect("Failed to create TimerPreset")
+    }
+
+    pub fn name(&self) -> String {
+        self.property_value("name").get::<String>().unwrap()
+    }
+
+    pub fn duration(&self) -> f64 {
+        self.property_value("duration").get::<f64>().unwrap()
+    }
+}
+
+impl Default for TimerPreset {
+    fn default() -> Self {
+        Self::new("Timer", 0.0)
+    }
+}
@@ -0,0 +1,46 @@
+use gtk::{glib, prelude::*, TickCallbackId, Widget};
+use std::cell::RefCell;
+
+/// A frame-clock-aligned replacement for a fixed-interval polling timeout.
+///
+/// Rather than waking up on a wall-clock interval regardless of whether
+/// the display actually redraws, `Timer` rides the widget's `FrameClock`
+/// via `add_tick_callback`, so the driven callback runs once per frame
+/// instead of hundreds or thousands of times a second.
+#[derive(Default)]
+pub struct Timer {
+    tick_id: RefCell<Option<TickCallbackId>>,
+}
+
+impl Timer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// (Re)schedules `callback` to run on every frame tick of `widget`,
+    /// replacing any callback previously started on this token. Return
+    /// `false` from `callback` to stop the timer (e.g. once a countdown
+    /// reaches zero); return `true` to keep it running.
+    pub fn start<F>(&self, widget: &impl IsA<Widget>, mut callback: F)
+    where
+        F: FnMut() -> bool + 'static,
+    {
+        self.stop();
+
+        let id = widget.as_ref().add_tick_callback(move |_, _| {
+            if callback() {
+                glib::Continue(true)
+            } else {
+                glib::Continue(false)
+            }
+        });
+        self.tick_id.replace(Some(id));
+    }
+
+    /// Clears the scheduled tick callback, if any.
+    pub fn stop(&self) {
+        if let Some(id) = self.tick_id.borrow_mut().take() {
+            id.remove();
+        }
+    }
+}
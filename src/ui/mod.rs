@@ -1,5 +1,6 @@
 pub mod clocks;
 pub mod stopwatch;
+pub mod timers;
 
 pub mod widgets {
     pub mod clock_location_row;
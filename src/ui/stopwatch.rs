@@ -11,20 +11,21 @@ impl Default for State {
 }
 
 mod imp {
-    use chrono::Duration;
     use gtk::{
         gio::ListStore,
-        glib::{self, clone, subclass::InitializingObject, timeout_add_local},
+        glib::{self, clone, subclass::InitializingObject},
         prelude::*,
         subclass::prelude::*,
-        template_callbacks, Box, Button, CompositeTemplate, Label,
+        template_callbacks, Box, Button, CompositeTemplate, Label, ListBox,
     };
     use he::{traits::ButtonExt as HeButtonExt, Colors, FillButton};
     use log::debug;
-    use std::cell::Cell;
+    use std::cell::{Cell, RefCell};
     use stopwatch::Stopwatch;
 
-    use crate::lap::StopwatchLap;
+    use crate::duration::render_duration;
+    use crate::lap::{lap_delta, lap_extrema, StopwatchLap};
+    use crate::timer::Timer;
 
     use super::State;
 
@@ -48,10 +49,16 @@ mod imp {
         #[template_child]
         pub clear_btn: TemplateChild<FillButton>,
 
+        #[template_child]
+        pub laps_list: TemplateChild<ListBox>,
+
         pub timer: Cell<Stopwatch>,
+        pub tick_timer: Timer,
         pub state: Cell<State>,
         pub laps: ListStore,
         pub current_lap: Cell<i32>,
+        pub displayed_time: RefCell<(String, String, String, String)>,
+        pub inhibit_cookie: Cell<Option<u32>>,
     }
 
     impl Default for StopwatchPage {
@@ -64,16 +71,57 @@ mod imp {
                 miliseconds_label: TemplateChild::default(),
                 start_btn: TemplateChild::default(),
                 clear_btn: TemplateChild::default(),
+                laps_list: TemplateChild::default(),
                 timer: Cell::new(Stopwatch::new()),
+                tick_timer: Timer::new(),
                 state: Cell::new(State::Stopped),
                 laps: ListStore::new(StopwatchLap::type_(&StopwatchLap::default())),
                 current_lap: Cell::new(0),
+                displayed_time: RefCell::new(Default::default()),
+                inhibit_cookie: Cell::new(None),
             }
         }
     }
 
     #[template_callbacks]
     impl StopwatchPage {
+        fn application(&self) -> Option<gtk::Application> {
+            self.time_container
+                .root()
+                .and_then(|root| root.downcast::<gtk::Window>().ok())
+                .and_then(|window| window.application())
+        }
+
+        /// Holds the session awake for as long as the stopwatch is
+        /// running, so walking away doesn't let idle-suspend freeze the
+        /// count. A no-op if already inhibited.
+        fn inhibit(&self) {
+            if self.inhibit_cookie.get().is_some() {
+                return;
+            }
+
+            if let Some(app) = self.application() {
+                let window = self
+                    .time_container
+                    .root()
+                    .and_then(|root| root.downcast::<gtk::Window>().ok());
+                let cookie = app.inhibit(
+                    window.as_ref(),
+                    gtk::ApplicationInhibitFlags::SUSPEND | gtk::ApplicationInhibitFlags::IDLE,
+                    Some("Stopwatch is running"),
+                );
+                self.inhibit_cookie.replace(Some(cookie));
+            }
+        }
+
+        fn uninhibit(&self) {
+            if let Some(cookie) = self.inhibit_cookie.take() {
+                if let Some(app) = self.application() {
+                    app.uninhibit(cookie);
+                }
+            }
+        }
+
         fn start(&self) {
             let mut sw = self.timer.get();
             sw.start();
@@ -90,6 +138,8 @@ mod imp {
             self.time_container.add_css_class("running-stopwatch");
             self.time_container.remove_css_class("paused-stopwatch");
             self.time_container.remove_css_class("stopped-stopwatch");
+
+            self.inhibit();
         }
 
         fn stop(&self) {
@@ -109,6 +159,8 @@ mod imp {
             self.time_container.add_css_class("paused-stopwatch");
             self.time_container.remove_css_class("running-stopwatch");
             self.time_container.remove_css_class("stopped-stopwatch");
+
+            self.uninhibit();
         }
 
         fn clear(&self) {
@@ -127,6 +179,11 @@ mod imp {
             self.time_container.add_css_class("stopped-stopwatch");
             self.time_container.remove_css_class("running-stopwatch");
             self.time_container.remove_css_class("paused-stopwatch");
+
+            self.laps.remove_all();
+            self.current_lap.replace(0);
+
+            self.uninhibit();
         }
 
         fn total_laps_duration(&self) -> f64 {
@@ -150,21 +207,74 @@ mod imp {
             let time = self.timer.get().elapsed().as_secs_f64();
             let duration = time - self.total_laps_duration();
             let lap = StopwatchLap::new(duration, self.current_lap.get());
+
+            let previous_duration = self.laps.item(0).map(|previous| {
+                previous
+                    .downcast_ref::<StopwatchLap>()
+                    .expect("Item should be of type 'StopwatchLap'")
+                    .property_value("duration")
+                    .get::<f64>()
+                    .unwrap()
+            });
+            if let Some(delta) = lap_delta(duration, previous_duration) {
+                lap.set_delta(delta);
+            }
+
             self.laps.insert(0, &lap);
+            self.update_lap_extrema();
+        }
+
+        /// Recomputes the fastest/slowest laps over the full lap list and
+        /// tags the rows holding them (every tied row, and none while
+        /// there are fewer than two laps), so split rendering can
+        /// highlight them with `fastest-lap`/`slowest-lap` CSS classes.
+        fn update_lap_extrema(&self) {
+            let laps: Vec<StopwatchLap> = (0..self.laps.n_items())
+                .map(|i| {
+                    self.laps
+                        .item(i)
+                        .unwrap()
+                        .downcast::<StopwatchLap>()
+                        .expect("Item should be of type 'StopwatchLap'")
+                })
+                .collect();
+            let durations: Vec<f64> = laps
+                .iter()
+                .map(|lap| lap.property_value("duration").get::<f64>().unwrap())
+                .collect();
+
+            for lap in &laps {
+                lap.set_fastest(false);
+                lap.set_slowest(false);
+            }
+
+            let (fastest, slowest) = lap_extrema(&durations);
+            for i in fastest {
+                laps[i].set_fastest(true);
+            }
+            for i in slowest {
+                laps[i].set_slowest(true);
+            }
         }
 
         pub fn update_time(&self) {
-            let duration = Duration::from_std(self.timer.get().elapsed()).unwrap();
+            let rendered = render_duration(self.timer.get().elapsed().as_secs_f64());
 
-            let ms = (duration.num_milliseconds() / 100) % 10;
+            let displayed = (
+                rendered.hours.clone(),
+                rendered.minutes.clone(),
+                rendered.seconds.clone(),
+                rendered.centiseconds.clone(),
+            );
+            if *self.displayed_time.borrow() == displayed {
+                return;
+            }
+            self.displayed_time.replace(displayed);
 
-            self.hours_label
-                .set_label(&format!("{}\u{200E}", duration.num_hours()));
-            self.minutes_label
-                .set_label(&format!("{}\u{200E}", duration.num_minutes()));
-            self.seconds_label
-                .set_label(&format!("{}\u{200E}", duration.num_seconds()));
-            self.miliseconds_label.set_label(&format!("{}", ms));
+            self.hours_label.set_label(&rendered.hours);
+            self.minutes_label.set_label(&rendered.minutes);
+            self.seconds_label.set_label(&rendered.seconds);
+            self.miliseconds_label.set_label(&rendered.centiseconds);
         }
 
         #[template_callback]
@@ -211,22 +321,84 @@ mod imp {
 
             self.timer.replace(Stopwatch::new());
 
-            // TODO move this into its own Rust object
-            timeout_add_local(
-                std::time::Duration::from_millis(1),
-                clone!(@weak obj => @default-return Continue(false), move || {
+            self.laps_list.bind_model(Some(&self.laps), |item| {
+                let lap = item
+                    .downcast_ref::<StopwatchLap>()
+                    .expect("Item should be of type 'StopwatchLap'")
+                    .clone();
+
+                let rendered = lap.rendered_duration();
+                let mut text = format!(
+                    "{}:{}:{}.{}",
+                    rendered.hours, rendered.minutes, rendered.seconds, rendered.centiseconds
+                );
+
+                let delta = lap.property_value("delta").get::<f64>().unwrap();
+                if let Some(delta_rendered) = lap.rendered_delta() {
+                    let sign = if delta < 0.0 { "-" } else { "+" };
+                    text.push_str(&format!(
+                        " ({}{}:{}:{})",
+                        sign, delta_rendered.hours, delta_rendered.minutes, delta_rendered.seconds
+                    ));
+                }
+
+                let row = Label::new(Some(&text));
+                row.set_halign(gtk::Align::Start);
+
+                if lap.property_value("has-delta").get::<bool>().unwrap() {
+                    row.add_css_class(if delta < 0.0 {
+                        "negative-lap"
+                    } else {
+                        "positive-lap"
+                    });
+                }
+
+                // `is-fastest`/`is-slowest` are re-tagged on the extremal
+                // laps every time a new lap is recorded, so each row watches
+                // for changes instead of only reading the value once.
+                fn sync_extremum(lap: &StopwatchLap, row: &Label, property: &str, css_class: &str) {
+                    if lap.property_value(property).get::<bool>().unwrap() {
+                        row.add_css_class(css_class);
+                    } else {
+                        row.remove_css_class(css_class);
+                    }
+                }
+
+                sync_extremum(&lap, &row, "is-fastest", "fastest-lap");
+                sync_extremum(&lap, &row, "is-slowest", "slowest-lap");
+
+                lap.connect_notify_local(
+                    Some("is-fastest"),
+                    clone!(@weak row => move |lap, _| sync_extremum(lap, &row, "is-fastest", "fastest-lap")),
+                );
+                lap.connect_notify_local(
+                    Some("is-slowest"),
+                    clone!(@weak row => move |lap, _| sync_extremum(lap, &row, "is-slowest", "slowest-lap")),
+                );
+
+                row.upcast()
+            });
+
+            self.tick_timer.start(
+                obj,
+                clone!(@weak obj => @default-return false, move || {
                     match obj.imp().state.get() {
                         State::Running => obj.imp().update_time(),
                         State::Reset => obj.imp().update_time(),
                         _ => {}
                     }
-                    Continue(true)
+                    true
                 }),
             );
 
             obj.connect_realize(move |_| {
                 debug!("GtkBox<StopwatchPage>::realize");
             });
+
+            obj.connect_unrealize(clone!(@weak obj => move |_| {
+                debug!("GtkBox<StopwatchPage>::unrealize");
+                obj.imp().uninhibit();
+            }));
         }
     }
 
@@ -248,4 +420,4 @@ impl StopwatchPage {
     pub fn new() -> Self {
         Object::new(&[]).expect("Failed to create StopwatchPage")
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,342 @@
+mod imp {
+    use gtk::{
+        gio::{ListStore, Notification},
+        glib::{self, clone, subclass::InitializingObject},
+        prelude::*,
+        subclass::prelude::*,
+        template_callbacks, Box, Button, CompositeTemplate, Entry, Label, ListBox, ListBoxRow,
+        Orientation,
+    };
+    use he::{traits::ButtonExt as HeButtonExt, Colors, FillButton};
+    use log::debug;
+    use std::cell::{Cell, RefCell};
+    use stopwatch::Stopwatch;
+
+    use crate::audio::{alert_sender, Alert};
+    use crate::duration::render_duration;
+    use crate::preset::TimerPreset;
+    use crate::timer::Timer;
+    use crate::ui::stopwatch::State;
+
+    #[derive(CompositeTemplate)]
+    #[template(resource = "/co/tauos/Nixie/timer.ui")]
+    pub struct TimerPage {
+        #[template_child]
+        pub time_container: TemplateChild<Box>,
+
+        #[template_child]
+        pub hours_label: TemplateChild<Label>,
+        #[template_child]
+        pub minutes_label: TemplateChild<Label>,
+        #[template_child]
+        pub seconds_label: TemplateChild<Label>,
+
+        #[template_child]
+        pub start_btn: TemplateChild<FillButton>,
+        #[template_child]
+        pub clear_btn: TemplateChild<FillButton>,
+
+        #[template_child]
+        pub presets_list: TemplateChild<ListBox>,
+        #[template_child]
+        pub preset_name_entry: TemplateChild<Entry>,
+        #[template_child]
+        pub preset_minutes_entry: TemplateChild<Entry>,
+        #[template_child]
+        pub add_preset_btn: TemplateChild<Button>,
+
+        pub timer: Cell<Stopwatch>,
+        pub tick_timer: Timer,
+        pub state: Cell<State>,
+        pub duration: Cell<f64>,
+        pub presets: ListStore,
+        pub displayed_time: RefCell<(String, String, String)>,
+    }
+
+    impl Default for TimerPage {
+        fn default() -> Self {
+            Self {
+                time_container: TemplateChild::default(),
+                hours_label: TemplateChild::default(),
+                minutes_label: TemplateChild::default(),
+                seconds_label: TemplateChild::default(),
+                start_btn: TemplateChild::default(),
+                clear_btn: TemplateChild::default(),
+                presets_list: TemplateChild::default(),
+                preset_name_entry: TemplateChild::default(),
+                preset_minutes_entry: TemplateChild::default(),
+                add_preset_btn: TemplateChild::default(),
+                timer: Cell::new(Stopwatch::new()),
+                tick_timer: Timer::new(),
+                state: Cell::new(State::Reset),
+                duration: Cell::new(0.0),
+                presets: ListStore::new(TimerPreset::static_type()),
+                displayed_time: RefCell::new(Default::default()),
+            }
+        }
+    }
+
+    #[template_callbacks]
+    impl TimerPage {
+        fn remaining(&self) -> f64 {
+            (self.duration.get() - self.timer.get().elapsed().as_secs_f64()).max(0.0)
+        }
+
+        fn start(&self) {
+            let mut sw = self.timer.get();
+            sw.start();
+            self.timer.replace(sw);
+            self.state.replace(State::Running);
+
+            self.start_btn.set_label("Pause");
+            self.start_btn.set_color(Colors::Yellow);
+
+            self.clear_btn.set_label("Cancel");
+            self.clear_btn.set_sensitive(true);
+            self.clear_btn.set_color(Colors::Red);
+
+            self.time_container.add_css_class("running-timer");
+            self.time_container.remove_css_class("paused-timer");
+            self.time_container.remove_css_class("stopped-timer");
+            self.time_container.remove_css_class("expired-timer");
+        }
+
+        fn stop(&self) {
+            let mut sw = self.timer.get();
+            sw.stop();
+            self.timer.replace(sw);
+            self.state.replace(State::Stopped);
+
+            self.start_btn.set_label("Resume");
+            self.start_btn.set_color(Colors::Purple);
+
+            self.clear_btn.set_label("Cancel");
+            self.clear_btn.set_sensitive(true);
+            self.clear_btn.set_color(Colors::Red);
+
+            self.time_container.add_css_class("paused-timer");
+            self.time_container.remove_css_class("running-timer");
+            self.time_container.remove_css_class("stopped-timer");
+        }
+
+        fn clear(&self) {
+            let mut sw = self.timer.get();
+            sw.reset();
+            self.timer.replace(sw);
+            self.state.replace(State::Reset);
+            self.duration.set(0.0);
+
+            self.start_btn.set_label("Start");
+            self.start_btn.set_color(Colors::Purple);
+            self.start_btn.set_sensitive(false);
+
+            self.clear_btn.set_label("Cancel");
+            self.clear_btn.set_sensitive(false);
+            self.clear_btn.set_color(Colors::Purple);
+
+            self.time_container.add_css_class("stopped-timer");
+            self.time_container.remove_css_class("running-timer");
+            self.time_container.remove_css_class("paused-timer");
+            self.time_container.remove_css_class("expired-timer");
+
+            self.update_time();
+        }
+
+        /// Loads `duration` (in seconds) as the countdown length and
+        /// starts it immediately; called when a preset row is activated.
+        pub fn start_countdown(&self, duration: f64) {
+            let mut sw = Stopwatch::new();
+            sw.start();
+            self.timer.replace(sw);
+            self.duration.set(duration);
+            self.start_btn.set_sensitive(true);
+            self::TimerPage::start(self);
+        }
+
+        fn expire(&self) {
+            self.state.replace(State::Stopped);
+
+            self.start_btn.set_label("Start");
+            self.start_btn.set_color(Colors::Purple);
+            self.clear_btn.set_label("Cancel");
+            self.clear_btn.set_sensitive(true);
+
+            self.time_container.remove_css_class("running-timer");
+            self.time_container.add_css_class("expired-timer");
+
+            if alert_sender().send(Alert::TimerExpired).is_err() {
+                debug!("Alert sender has no receiver; dropping expiry sound");
+            }
+
+            let notification = Notification::new("Timer finished");
+            notification.set_body(Some("Your countdown has reached zero."));
+            if let Some(app) = self
+                .time_container
+                .root()
+                .and_then(|root| root.downcast::<gtk::Window>().ok())
+                .and_then(|window| window.application())
+            {
+                app.send_notification(Some("timer-expired"), &notification);
+            }
+        }
+
+        pub fn update_time(&self) {
+            let remaining = self.remaining();
+            let rendered = render_duration(remaining);
+
+            let displayed = (
+                rendered.hours.clone(),
+                rendered.minutes.clone(),
+                rendered.seconds.clone(),
+            );
+            if *self.displayed_time.borrow() != displayed {
+                self.displayed_time.replace(displayed);
+
+                self.hours_label.set_label(&rendered.hours);
+                self.minutes_label.set_label(&rendered.minutes);
+                self.seconds_label.set_label(&rendered.seconds);
+            }
+
+            if self.state.get() == State::Running && remaining <= 0.0 {
+                self.expire();
+            }
+        }
+
+        /// Adds a new named preset, persisted for the lifetime of the
+        /// `ListStore` backing `presets_list`.
+        pub fn add_preset(&self, name: &str, duration: f64) {
+            let preset = TimerPreset::new(name, duration);
+            self.presets.append(&preset);
+        }
+
+        pub fn remove_preset(&self, preset: &TimerPreset) {
+            if let Some(position) = self.presets.find(preset) {
+                self.presets.remove(position);
+            }
+        }
+
+        #[template_callback]
+        fn handle_on_start_btn_click(&self, _button: &Button) {
+            debug!("HeFillButton<TimerPage>::clicked");
+            match self.state.get() {
+                State::Reset => self::TimerPage::start(self),
+                State::Stopped => self::TimerPage::start(self),
+                State::Running => self::TimerPage::stop(self),
+            }
+        }
+
+        #[template_callback]
+        fn handle_on_clear_btn_click(&self, _button: &Button) {
+            debug!("HeFillButton<TimerPage>::clicked (clear-btn)");
+            self::TimerPage::clear(self);
+        }
+
+        #[template_callback]
+        fn handle_on_add_preset_btn_click(&self, _button: &Button) {
+            let name = self.preset_name_entry.text().to_string();
+            let minutes: f64 = self.preset_minutes_entry.text().parse().unwrap_or_default();
+
+            if name.is_empty() || minutes <= 0.0 {
+                return;
+            }
+
+            self.add_preset(&name, minutes * 60.0);
+            self.preset_name_entry.set_text("");
+            self.preset_minutes_entry.set_text("");
+        }
+
+        #[template_callback]
+        fn handle_on_preset_row_activated(&self, row: &ListBoxRow) {
+            let preset = self
+                .presets
+                .item(row.index() as u32)
+                .and_then(|item| item.downcast::<TimerPreset>().ok());
+
+            if let Some(preset) = preset {
+                self.start_countdown(preset.duration());
+            }
+        }
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for TimerPage {
+        const NAME: &'static str = "NixieTimerPage";
+        type Type = super::TimerPage;
+        type ParentType = gtk::Box;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+        }
+
+        fn instance_init(obj: &InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl BoxImpl for TimerPage {}
+    impl ObjectImpl for TimerPage {
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            self.presets_list.bind_model(
+                Some(&self.presets),
+                clone!(@weak obj => @default-panic, move |item| {
+                    let preset = item
+                        .downcast_ref::<TimerPreset>()
+                        .expect("Item should be of type 'TimerPreset'")
+                        .clone();
+
+                    let row = Box::new(Orientation::Horizontal, 6);
+
+                    let label = Label::new(Some(&preset.name()));
+                    label.set_halign(gtk::Align::Start);
+                    label.set_hexpand(true);
+                    row.append(&label);
+
+                    let remove_btn = Button::from_icon_name("edit-delete-symbolic");
+                    remove_btn.add_css_class("flat");
+                    remove_btn.connect_clicked(clone!(@weak obj, @strong preset => move |_| {
+                        obj.imp().remove_preset(&preset);
+                    }));
+                    row.append(&remove_btn);
+
+                    row.upcast()
+                }),
+            );
+
+            self.tick_timer.start(
+                obj,
+                clone!(@weak obj => @default-return false, move || {
+                    if obj.imp().state.get() == State::Running {
+                        obj.imp().update_time();
+                    }
+                    true
+                }),
+            );
+
+            obj.connect_realize(move |_| {
+                debug!("GtkBox<TimerPage>::realize");
+            });
+        }
+    }
+
+    impl WidgetImpl for TimerPage {}
+}
+
+use gtk::{
+    glib::{self, Object},
+    Accessible, Box, Buildable, ConstraintTarget, Widget,
+};
+
+glib::wrapper! {
+    pub struct TimerPage(ObjectSubclass<imp::TimerPage>)
+        @extends Box, Widget,
+        @implements Accessible, Buildable, ConstraintTarget;
+}
+
+impl TimerPage {
+    pub fn new() -> Self {
+        Object::new(&[]).expect("Failed to create TimerPage")
+    }
+}